@@ -0,0 +1,41 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2017-2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! System RPC module errors.
+
+use jsonrpsee::types::{CallError, Error as JsonRpseeError};
+
+/// System RPC Result type.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// System RPC errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// Provided peer id couldn't be parsed.
+	#[error("Error decoding peer id: {0}")]
+	MalformattedPeerArg(String),
+}
+
+/// Base error code for all system errors.
+const BASE_ERROR: i32 = 2000;
+
+impl From<Error> for JsonRpseeError {
+	fn from(e: Error) -> Self {
+		CallError::Custom { code: BASE_ERROR + 1, message: e.to_string(), data: None }.into()
+	}
+}
@@ -0,0 +1,182 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2017-2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate system API helpers.
+
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt};
+
+/// Node health.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Health {
+	/// Number of connected peers
+	pub peers: usize,
+	/// Is the node syncing
+	pub is_syncing: bool,
+	/// Should this node have any peers
+	///
+	/// Might be false for example in case of a manual seal blockchain.
+	pub should_have_peers: bool,
+	/// Estimated offset of the local system clock from true time, in milliseconds, as measured
+	/// against a set of configured NTP servers.
+	///
+	/// `None` if no sample has been taken yet (e.g. right after startup) or if clock-drift
+	/// checking is disabled.
+	pub clock_offset_ms: Option<i64>,
+}
+
+impl fmt::Display for Health {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		fmt.write_fmt(format_args!(
+			"{} peers ({})",
+			self.peers,
+			if self.is_syncing { "syncing" } else { "idle" }
+		))
+	}
+}
+
+/// Must be kept in sync with `sc-network::PeerInfo`.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerInfo<Hash, Number> {
+	/// Peer ID
+	pub peer_id: String,
+	/// Roles
+	pub roles: String,
+	/// Peer best block hash
+	pub best_hash: Hash,
+	/// Peer best block number
+	pub best_number: Number,
+}
+
+/// A page of `T`s together with the total number of items available, for paginated RPC methods
+/// such as `system_peers`.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+	/// The items in this page.
+	pub items: Vec<T>,
+	/// Total number of items available, irrespective of `offset`/`limit`.
+	pub total: usize,
+}
+
+/// Aggregate traffic and substream counters for a single networking protocol, as reported by
+/// `system_networkStats`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolStats {
+	/// Total bytes received for this protocol since startup.
+	pub bytes_in: u64,
+	/// Total bytes sent for this protocol since startup.
+	pub bytes_out: u64,
+	/// Instantaneous inbound rate, in bytes per second.
+	pub rate_in_bytes_per_sec: f64,
+	/// Instantaneous outbound rate, in bytes per second.
+	pub rate_out_bytes_per_sec: f64,
+	/// Number of currently open substreams for this protocol.
+	pub open_substreams: u32,
+}
+
+/// Stable, aggregate networking statistics, returned by `system_networkStats` as a documented
+/// alternative to the unstable `system_network_state` blob.
+///
+/// This type is intended to evolve under semver: new fields may be added in minor releases, but
+/// existing fields keep their name and meaning.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkStats {
+	/// Total number of open connections, inbound and outbound.
+	pub total_connections: u32,
+	/// Number of open inbound connections.
+	pub inbound_connections: u32,
+	/// Number of open outbound connections.
+	pub outbound_connections: u32,
+	/// Number of connected peers that are configured as reserved peers.
+	pub reserved_peers: u32,
+	/// Number of connected peers that were found through discovery (not reserved).
+	pub discovered_peers: u32,
+	/// Per-protocol traffic and substream counters, keyed by protocol name.
+	pub protocols: BTreeMap<String, ProtocolStats>,
+}
+
+/// A change to the set of connected peers, as streamed by `system_subscribePeers`.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum PeerEvent<Hash, Number> {
+	/// A peer connected. Also sent once for every already-connected peer right after
+	/// subscribing.
+	Connected(PeerInfo<Hash, Number>),
+	/// A peer disconnected.
+	Disconnected {
+		/// Base58-encoded PeerId of the peer that disconnected.
+		#[serde(rename = "peerId")]
+		peer_id: String,
+	},
+	/// An already-connected peer changed, e.g. its best block or role.
+	Updated(PeerInfo<Hash, Number>),
+}
+
+/// A peer currently blocked by the network backend's reputation system.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BannedPeer {
+	/// Base58-encoded PeerId of the banned peer.
+	pub peer_id: String,
+	/// Current reputation score of the peer.
+	pub reputation: i32,
+	/// Number of seconds remaining before the ban expires.
+	pub ban_duration_secs: u64,
+}
+
+/// The role the node is running as
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NodeRole {
+	/// The node is a full node
+	Full,
+	/// The node is a light client
+	Light,
+	/// The node is an authority
+	Authority,
+}
+
+/// The state of the syncing of the node.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncState<Number> {
+	/// Height of the block at which syncing started.
+	pub starting_block: Number,
+	/// Height of the current best block of the node.
+	pub current_block: Number,
+	/// Height of the highest block in the network.
+	pub highest_block: Option<Number>,
+}
+
+/// Summary of the node's chain and implementation, shared with peers via the network.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemInfo {
+	/// Implementation name.
+	pub impl_name: String,
+	/// Implementation version.
+	pub impl_version: String,
+	/// Chain name.
+	pub chain_name: String,
+}
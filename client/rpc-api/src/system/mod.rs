@@ -23,7 +23,9 @@ use jsonrpsee::{
 	types::{JsonRpcResult, JsonValue},
 };
 
-pub use self::helpers::{Health, NodeRole, PeerInfo, SyncState, SystemInfo};
+pub use self::helpers::{
+	BannedPeer, Health, NetworkStats, NodeRole, Page, PeerEvent, PeerInfo, SyncState, SystemInfo,
+};
 
 pub mod error;
 pub mod helpers;
@@ -56,9 +58,18 @@ pub trait SystemApi<Hash, Number> {
 	/// Node is considered healthy if it is:
 	/// - connected to some peers (unless running in dev mode)
 	/// - not performing a major sync
+	/// - not suffering from a system clock drift beyond the configured bound
 	#[method(name = "health")]
 	async fn system_health(&self) -> JsonRpcResult<Health>;
 
+	/// Returns the node's last-measured system clock offset from true time, in milliseconds, as
+	/// estimated against a set of configured NTP servers.
+	///
+	/// A positive value means the local clock is ahead of true time. The measurement is taken
+	/// periodically on a background timer, so this call never blocks on network I/O.
+	#[method(name = "clockOffset")]
+	async fn system_clock_offset(&self) -> JsonRpcResult<i64>;
+
 	/// Returns the base58-encoded PeerId of the node.
 	#[method(name = "localPeerId")]
 	async fn system_local_peer_id(&self) -> JsonRpcResult<String>;
@@ -70,19 +81,49 @@ pub trait SystemApi<Hash, Number> {
 	#[method(name = "localListenAddresses")]
 	async fn system_local_listen_addresses(&self) -> JsonRpcResult<Vec<String>>;
 
-	/// Returns currently connected peers
+	/// Returns currently connected peers, optionally paginated with `offset`/`limit`.
+	///
+	/// **Breaking change**: the response is now a `{ items, total }` object rather than a bare
+	/// array, even when `offset`/`limit` are omitted — `items` holds the (possibly sliced) peer
+	/// list and `total` always reflects the full peer count, regardless of pagination.
 	#[method(name = "peers")]
-	async fn system_peers(&self) -> JsonRpcResult<Vec<PeerInfo<Hash, Number>>>;
+	async fn system_peers(
+		&self,
+		offset: Option<usize>,
+		limit: Option<usize>,
+	) -> JsonRpcResult<Page<PeerInfo<Hash, Number>>>;
+
+	/// Subscribe to changes in the set of connected peers.
+	///
+	/// On subscription, the full current peer set is sent as a burst of `Connected` events,
+	/// followed by `Connected`/`Disconnected`/`Updated` deltas as peers come and go or their
+	/// best block/role changes. This lets a client maintain a live peer table without
+	/// repeatedly polling `system_peers`.
+	#[subscription(
+		name = "subscribePeers" => "peers",
+		unsubscribe = "unsubscribePeers",
+		item = PeerEvent<Hash, Number>
+	)]
+	fn subscribe_peers(&self);
 
 	/// Returns current state of the network.
 	///
 	/// **Warning**: This API is not stable. Please do not programmatically interpret its output,
-	/// as its format might change at any time.
+	/// as its format might change at any time. Prefer `system_networkStats` for a stable,
+	/// documented surface.
 	// TODO: the future of this call is uncertain: https://github.com/paritytech/substrate/issues/1890
 	// https://github.com/paritytech/substrate/issues/5541
 	#[method(name = "unstable_networkState")]
 	async fn system_network_state(&self) -> JsonRpcResult<JsonValue>;
 
+	/// Returns stable, aggregate networking statistics: connection counts, per-protocol
+	/// traffic and substream counters, and the reserved/discovered peer split.
+	///
+	/// Unlike `unstable_networkState`, this is a typed, versioned surface that can evolve
+	/// under semver rather than depending on a dump of internal network-backend state.
+	#[method(name = "networkStats")]
+	async fn system_network_stats(&self) -> JsonRpcResult<NetworkStats>;
+
 	/// Adds a reserved peer. Returns the empty string or an error. The string
 	/// parameter should encode a `p2p` multiaddr.
 	///
@@ -100,6 +141,21 @@ pub trait SystemApi<Hash, Number> {
 	#[method(name = "reservedPeers")]
 	async fn system_reserved_peers(&self) -> JsonRpcResult<Vec<String>>;
 
+	/// Returns the network backend's current reputation score for the given peer. The string
+	/// should encode only the PeerId e.g. `QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV`.
+	#[method(name = "reputation")]
+	async fn system_reputation(&self, peer_id: String) -> JsonRpcResult<i32>;
+
+	/// Returns the list of peers currently blocked by the network backend's reputation system,
+	/// along with their current reputation and remaining ban duration.
+	#[method(name = "bannedPeers")]
+	async fn system_banned_peers(&self) -> JsonRpcResult<Vec<BannedPeer>>;
+
+	/// Lifts a reputation ban on a peer before it would otherwise expire. The string should
+	/// encode only the PeerId e.g. `QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV`.
+	#[method(name = "unbanPeer")]
+	async fn system_unban_peer(&self, peer_id: String) -> JsonRpcResult<()>;
+
 	/// Returns the roles the node is running as.
 	#[method(name = "nodeRoles")]
 	async fn system_node_roles(&self) -> JsonRpcResult<Vec<NodeRole>>;
@@ -109,6 +165,18 @@ pub trait SystemApi<Hash, Number> {
 	#[method(name = "syncState")]
 	async fn system_sync_state(&self) -> JsonRpcResult<SyncState<Number>>;
 
+	/// Subscribe to the node's sync state.
+	///
+	/// The current `SyncState` is sent immediately on subscription, and a new one is pushed
+	/// every time the best or highest-known block advances. Subsequent notifications are
+	/// skipped if nothing in the `SyncState` actually changed since the last one sent.
+	#[subscription(
+		name = "subscribeSyncState" => "syncState",
+		unsubscribe = "unsubscribeSyncState",
+		item = SyncState<Number>
+	)]
+	fn subscribe_sync_state(&self);
+
 	/// Adds the supplied directives to the current log filter
 	///
 	/// The syntax is identical to the CLI `<target>=<level>`: